@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::path_reconstruction::reconstruct_path;
+
 #[derive(Clone, Debug)]
 pub struct Node {
     pub id: i32,
@@ -14,10 +16,20 @@ pub struct Edge {
     pub weight: i32,
 }
 
+// A negative cycle reachable from the source makes "shortest path" undefined,
+// since walking the cycle again always lowers the cost further. `node_id` is
+// a node that was still relaxable after `nodes.len() - 1` rounds, i.e. one
+// node callers can start from to walk the cycle back out.
+#[derive(Debug, Eq, PartialEq)]
+pub struct NegativeCycle {
+    pub node_id: i32,
+}
+
 #[derive(Debug)]
 pub struct Graph {
     pub nodes: HashMap<i32, Node>,
     pub edges: HashMap<i32, Vec<Edge>>,
+    directed: bool,
 }
 
 impl Graph {
@@ -25,6 +37,18 @@ impl Graph {
         Graph {
             nodes: HashMap::new(),
             edges: HashMap::new(),
+            directed: false,
+        }
+    }
+
+    // Like `new`, but `add_edge` no longer inserts the implicit reverse edge,
+    // which is what makes negative weights usable without instantly forming
+    // a negative cycle across every edge.
+    pub fn directed() -> Self {
+        Graph {
+            nodes: HashMap::new(),
+            edges: HashMap::new(),
+            directed: true,
         }
     }
 
@@ -38,6 +62,10 @@ impl Graph {
             .or_default()
             .push(edge.clone());
 
+        if self.directed {
+            return;
+        }
+
         let reverse_edge = Edge {
             node_a_id: edge.node_b_id,
             node_b_id: edge.node_a_id,
@@ -49,10 +77,55 @@ impl Graph {
             .push(reverse_edge);
     }
 
-    pub fn bellman_ford(&self, from_node_id: i32, to_node_id: i32) -> i32 {
+    pub fn bellman_ford(&self, from_node_id: i32, to_node_id: i32) -> Result<i32, NegativeCycle> {
         let mut distances = HashMap::new();
         distances.insert(from_node_id, 0);
 
+        for _ in 0..self.nodes.len().saturating_sub(1) {
+            for node_id in self.nodes.keys() {
+                if let Some(edges) = self.edges.get(node_id) {
+                    for edge in edges {
+                        let new_distance = distances
+                            .get(node_id)
+                            .and_then(|d: &i32| d.checked_add(edge.weight))
+                            .unwrap_or(i32::MAX);
+                        let current_distance = distances.get(&edge.node_b_id).unwrap_or(&i32::MAX);
+                        if new_distance < *current_distance {
+                            distances.insert(edge.node_b_id, new_distance);
+                        }
+                    }
+                }
+            }
+        }
+
+        // One more round: if anything can still relax, a negative cycle is reachable.
+        for node_id in self.nodes.keys() {
+            if let Some(edges) = self.edges.get(node_id) {
+                for edge in edges {
+                    let new_distance = distances
+                        .get(node_id)
+                        .and_then(|d: &i32| d.checked_add(edge.weight))
+                        .unwrap_or(i32::MAX);
+                    let current_distance = distances.get(&edge.node_b_id).unwrap_or(&i32::MAX);
+                    if new_distance < *current_distance {
+                        return Err(NegativeCycle { node_id: edge.node_b_id });
+                    }
+                }
+            }
+        }
+
+        Ok(distances.get(&to_node_id).cloned().unwrap_or(i32::MAX))
+    }
+
+    pub fn shortest_path(&self, from_node_id: i32, to_node_id: i32) -> Option<Vec<i32>> {
+        self.shortest_path_with_cost(from_node_id, to_node_id).map(|(path, _)| path)
+    }
+
+    pub fn shortest_path_with_cost(&self, from_node_id: i32, to_node_id: i32) -> Option<(Vec<i32>, i32)> {
+        let mut distances = HashMap::new();
+        let mut predecessors: HashMap<i32, i32> = HashMap::new();
+        distances.insert(from_node_id, 0);
+
         for _ in 0..self.nodes.len() {
             for node_id in self.nodes.keys() {
                 if let Some(edges) = self.edges.get(node_id) {
@@ -64,13 +137,78 @@ impl Graph {
                         let current_distance = distances.get(&edge.node_b_id).unwrap_or(&i32::MAX);
                         if new_distance < *current_distance {
                             distances.insert(edge.node_b_id, new_distance);
+                            predecessors.insert(edge.node_b_id, *node_id);
                         }
                     }
                 }
             }
         }
 
-        distances.get(&to_node_id).cloned().unwrap_or(i32::MAX)
+        // One more round: if anything can still relax, a negative cycle is reachable
+        // from `from_node_id`, and `predecessors` may contain a cycle of its own.
+        for node_id in self.nodes.keys() {
+            if let Some(edges) = self.edges.get(node_id) {
+                for edge in edges {
+                    let new_distance = distances
+                        .get(node_id)
+                        .and_then(|d: &i32| d.checked_add(edge.weight))
+                        .unwrap_or(i32::MAX);
+                    let current_distance = distances.get(&edge.node_b_id).unwrap_or(&i32::MAX);
+                    if new_distance < *current_distance {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        let total_cost = *distances.get(&to_node_id)?;
+        reconstruct_path(&predecessors, from_node_id, to_node_id, total_cost)
+    }
+
+    // Floyd-Warshall: O(V^3) all-pairs shortest distances.
+    pub fn all_pairs_shortest_paths(&self) -> HashMap<(i32, i32), i32> {
+        let ids: Vec<i32> = self.nodes.keys().cloned().collect();
+        let n = ids.len();
+        let index: HashMap<i32, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut dist = vec![vec![i32::MAX; n]; n];
+        for (i, diagonal) in dist.iter_mut().enumerate() {
+            diagonal[i] = 0;
+        }
+        for (node_id, edges) in &self.edges {
+            let i = index[node_id];
+            for edge in edges {
+                let j = index[&edge.node_b_id];
+                if edge.weight < dist[i][j] {
+                    dist[i][j] = edge.weight;
+                }
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                if dist[i][k] == i32::MAX {
+                    continue;
+                }
+                for j in 0..n {
+                    if dist[k][j] == i32::MAX {
+                        continue;
+                    }
+                    let through_k = dist[i][k] + dist[k][j];
+                    if through_k < dist[i][j] {
+                        dist[i][j] = through_k;
+                    }
+                }
+            }
+        }
+
+        let mut result = HashMap::new();
+        for (i, &from_id) in ids.iter().enumerate() {
+            for (j, &to_id) in ids.iter().enumerate() {
+                result.insert((from_id, to_id), dist[i][j]);
+            }
+        }
+        result
     }
 }
 
@@ -83,8 +221,8 @@ mod tests {
         let mut graph = Graph::new();
         graph.add_node(Node { id: 1, x: 0, y: 0 });
 
-        assert_eq!(graph.bellman_ford(1, 1), 0);
-        assert_eq!(graph.bellman_ford(1, 2), i32::MAX);
+        assert_eq!(graph.bellman_ford(1, 1), Ok(0));
+        assert_eq!(graph.bellman_ford(1, 2), Ok(i32::MAX));
     }
 
     #[test]
@@ -99,8 +237,8 @@ mod tests {
             weight: 10,
         });
 
-        assert_eq!(graph.bellman_ford(1, 2), 10);
-        assert_eq!(graph.bellman_ford(2, 1), 10);
+        assert_eq!(graph.bellman_ford(1, 2), Ok(10));
+        assert_eq!(graph.bellman_ford(2, 1), Ok(10));
     }
 
     #[test]
@@ -132,8 +270,182 @@ mod tests {
             weight: 20,
         });
 
-        assert_eq!(graph.bellman_ford(1, 4), 18);
-        assert_eq!(graph.bellman_ford(1, 3), 15);
-        assert_eq!(graph.bellman_ford(2, 4), 13);
+        assert_eq!(graph.bellman_ford(1, 4), Ok(18));
+        assert_eq!(graph.bellman_ford(1, 3), Ok(15));
+        assert_eq!(graph.bellman_ford(2, 4), Ok(13));
+    }
+
+    #[test]
+    fn test_directed_edge_has_no_reverse() {
+        let mut graph = Graph::directed();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 2,
+            weight: 10,
+        });
+
+        assert_eq!(graph.bellman_ford(1, 2), Ok(10));
+        assert_eq!(graph.bellman_ford(2, 1), Ok(i32::MAX));
+    }
+
+    #[test]
+    fn test_negative_cycle_is_detected() {
+        let mut graph = Graph::directed();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 2,
+            weight: 1,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 2,
+            node_b_id: 3,
+            weight: -2,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 3,
+            node_b_id: 1,
+            weight: -2,
+        });
+
+        assert!(graph.bellman_ford(1, 3).is_err());
+    }
+
+    #[test]
+    fn test_shortest_path_with_cost_rejects_negative_cycle() {
+        let mut graph = Graph::directed();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 2,
+            weight: 1,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 2,
+            node_b_id: 3,
+            weight: -2,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 3,
+            node_b_id: 1,
+            weight: -2,
+        });
+
+        assert_eq!(graph.shortest_path_with_cost(1, 3), None);
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+
+        assert_eq!(graph.shortest_path(1, 2), None);
+    }
+
+    #[test]
+    fn test_shortest_path_returns_route_and_cost() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+        graph.add_node(Node { id: 4, x: 3, y: 3 });
+
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 2,
+            weight: 5,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 2,
+            node_b_id: 3,
+            weight: 10,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 3,
+            node_b_id: 4,
+            weight: 3,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 4,
+            weight: 20,
+        });
+
+        assert_eq!(graph.shortest_path(1, 4), Some(vec![1, 2, 3, 4]));
+        assert_eq!(
+            graph.shortest_path_with_cost(1, 4),
+            Some((vec![1, 2, 3, 4], 18))
+        );
+    }
+
+    #[test]
+    fn test_all_pairs_shortest_paths_matches_bellman_ford() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+        graph.add_node(Node { id: 4, x: 3, y: 3 });
+
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 2,
+            weight: 5,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 2,
+            node_b_id: 3,
+            weight: 10,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 3,
+            node_b_id: 4,
+            weight: 3,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 4,
+            weight: 20,
+        });
+
+        let all_pairs = graph.all_pairs_shortest_paths();
+
+        assert_eq!(all_pairs[&(1, 1)], 0);
+        assert_eq!(all_pairs[&(1, 4)], 18);
+        assert_eq!(all_pairs[&(1, 3)], 15);
+        assert_eq!(all_pairs[&(2, 4)], 13);
+    }
+
+    #[test]
+    fn test_all_pairs_shortest_paths_handles_directed_negative_edges() {
+        let mut graph = Graph::directed();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 2,
+            weight: 4,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 2,
+            node_b_id: 3,
+            weight: -2,
+        });
+
+        let all_pairs = graph.all_pairs_shortest_paths();
+
+        assert_eq!(all_pairs[&(1, 3)], 2);
+        assert_eq!(all_pairs[&(3, 1)], i32::MAX);
     }
 }
\ No newline at end of file
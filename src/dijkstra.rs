@@ -1,6 +1,10 @@
 use std::cmp::Ordering;
+use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::path_reconstruction::reconstruct_path;
 
 #[derive(Clone, Debug)]
 pub struct Node {
@@ -36,10 +40,52 @@ impl PartialOrd for State {
     }
 }
 
+// Disjoint-set over dense `0..n` indices, with path compression and union by
+// rank, backing both `minimum_spanning_tree` and `connected_components`.
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(size: usize) -> Self {
+        UnionFind {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    // Returns true if `a` and `b` were in different components (and are now merged).
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+
+        match self.rank[root_a].cmp(&self.rank[root_b]) {
+            Ordering::Less => self.parent[root_a] = root_b,
+            Ordering::Greater => self.parent[root_b] = root_a,
+            Ordering::Equal => {
+                self.parent[root_b] = root_a;
+                self.rank[root_a] += 1;
+            }
+        }
+        true
+    }
+}
+
 #[derive(Debug)]
 pub struct Graph {
     pub nodes: HashMap<i32, Node>,
     pub edges: HashMap<i32, Vec<Edge>>,
+    directed: bool,
 }
 
 impl Graph {
@@ -47,6 +93,16 @@ impl Graph {
         Graph {
             nodes: HashMap::new(),
             edges: HashMap::new(),
+            directed: false,
+        }
+    }
+
+    // Like `new`, but `add_edge` no longer inserts the implicit reverse edge.
+    pub fn directed() -> Self {
+        Graph {
+            nodes: HashMap::new(),
+            edges: HashMap::new(),
+            directed: true,
         }
     }
 
@@ -60,6 +116,10 @@ impl Graph {
             .or_default()
             .push(edge.clone());
 
+        if self.directed {
+            return;
+        }
+
         let reverse_edge = Edge {
             node_a_id: edge.node_b_id,
             node_b_id: edge.node_a_id,
@@ -135,6 +195,347 @@ impl Graph {
         distances.get(&to_node_id).cloned().unwrap_or(i32::MAX)
     }
 
+    pub fn shortest_path(&self, from_node_id: i32, to_node_id: i32) -> Option<Vec<i32>> {
+        self.shortest_path_with_cost(from_node_id, to_node_id).map(|(path, _)| path)
+    }
+
+    pub fn shortest_path_with_cost(&self, from_node_id: i32, to_node_id: i32) -> Option<(Vec<i32>, i32)> {
+        if !self.nodes.contains_key(&from_node_id) || !self.nodes.contains_key(&to_node_id) {
+            return None;
+        }
+
+        let mut distances = HashMap::new();
+        let mut predecessors: HashMap<i32, i32> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        distances.insert(from_node_id, 0);
+        heap.push(State { cost: 0, position: from_node_id });
+
+        while let Some(State { cost, position }) = heap.pop() {
+            if let Some(&current_cost) = distances.get(&position) {
+                if cost > current_cost {
+                    continue;
+                }
+            }
+
+            if let Some(edges) = self.edges.get(&position) {
+                for edge in edges {
+                    let next_cost = cost + edge.weight;
+                    let next_position = edge.node_b_id;
+
+                    if next_cost < *distances.get(&next_position).unwrap_or(&i32::MAX) {
+                        heap.push(State { cost: next_cost, position: next_position });
+                        distances.insert(next_position, next_cost);
+                        predecessors.insert(next_position, position);
+                    }
+                }
+            }
+        }
+
+        let total_cost = *distances.get(&to_node_id)?;
+        reconstruct_path(&predecessors, from_node_id, to_node_id, total_cost)
+    }
+
+    // Euclidean distance to the target, floored to an integer lower bound.
+    // Falls back to 0 for a node with no recorded coordinates.
+    fn heuristic(&self, node_id: i32, target_x: i32, target_y: i32) -> i32 {
+        match self.nodes.get(&node_id) {
+            Some(node) => {
+                let dx = (node.x - target_x) as f64;
+                let dy = (node.y - target_y) as f64;
+                dx.hypot(dy).floor() as i32
+            }
+            None => 0,
+        }
+    }
+
+    pub fn a_star(&self, from_node_id: i32, to_node_id: i32) -> i32 {
+        // If from_node_id or to_node_id does not exist, return i32::MAX.
+        if !self.nodes.contains_key(&from_node_id) || !self.nodes.contains_key(&to_node_id) {
+            return i32::MAX;
+        }
+
+        let target = &self.nodes[&to_node_id];
+        let (target_x, target_y) = (target.x, target.y);
+
+        let mut distances = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        // The distance to the start node is 0
+        distances.insert(from_node_id, 0);
+        heap.push(State {
+            cost: self.heuristic(from_node_id, target_x, target_y),
+            position: from_node_id,
+        });
+
+        // While there are nodes to process
+        while let Some(State { cost, position }) = heap.pop() {
+            // Recover the real accumulated cost `g` from the priority `cost = g + h`.
+            let g = cost - self.heuristic(position, target_x, target_y);
+
+            // We reached the target
+            if position == to_node_id {
+                return g;
+            }
+
+            // Skip this node if we've found a better way
+            if let Some(&current_cost) = distances.get(&position) {
+                if g > current_cost {
+                    continue;
+                }
+            }
+
+            // Next, we check each edge from the current node
+            if let Some(edges) = self.edges.get(&position) {
+                for edge in edges {
+                    let next_cost = g + edge.weight;
+                    let next_position = edge.node_b_id;
+
+                    // If we found a shorter path to the neighbor, then we continue
+                    if next_cost < *distances.get(&next_position).unwrap_or(&i32::MAX) {
+                        heap.push(State {
+                            cost: next_cost + self.heuristic(next_position, target_x, target_y),
+                            position: next_position,
+                        });
+                        distances.insert(next_position, next_cost);
+                    }
+                }
+            }
+        }
+
+        distances.get(&to_node_id).cloned().unwrap_or(i32::MAX)
+    }
+
+    // Sum of edge weights along consecutive nodes of `path`. The nodes are
+    // assumed to be connected, as they come from a path this module produced.
+    fn path_cost(&self, path: &[i32]) -> i32 {
+        path.windows(2)
+            .map(|pair| {
+                self.edges[&pair[0]]
+                    .iter()
+                    .find(|edge| edge.node_b_id == pair[1])
+                    .map(|edge| edge.weight)
+                    .unwrap_or(0)
+            })
+            .sum()
+    }
+
+    // Same relaxation loop as `shortest_path_with_cost`, but skipping `removed_nodes`/`removed_edges`.
+    fn dijkstra_excluding(
+        &self,
+        from_node_id: i32,
+        to_node_id: i32,
+        removed_nodes: &HashSet<i32>,
+        removed_edges: &HashSet<(i32, i32)>,
+    ) -> Option<(Vec<i32>, i32)> {
+        if removed_nodes.contains(&from_node_id) || removed_nodes.contains(&to_node_id) {
+            return None;
+        }
+
+        let mut distances = HashMap::new();
+        let mut predecessors: HashMap<i32, i32> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        distances.insert(from_node_id, 0);
+        heap.push(State { cost: 0, position: from_node_id });
+
+        while let Some(State { cost, position }) = heap.pop() {
+            if let Some(&current_cost) = distances.get(&position) {
+                if cost > current_cost {
+                    continue;
+                }
+            }
+
+            if let Some(edges) = self.edges.get(&position) {
+                for edge in edges {
+                    let next_position = edge.node_b_id;
+                    if removed_nodes.contains(&next_position)
+                        || removed_edges.contains(&(position, next_position))
+                    {
+                        continue;
+                    }
+
+                    let next_cost = cost + edge.weight;
+                    if next_cost < *distances.get(&next_position).unwrap_or(&i32::MAX) {
+                        heap.push(State { cost: next_cost, position: next_position });
+                        distances.insert(next_position, next_cost);
+                        predecessors.insert(next_position, position);
+                    }
+                }
+            }
+        }
+
+        let total_cost = *distances.get(&to_node_id)?;
+        reconstruct_path(&predecessors, from_node_id, to_node_id, total_cost)
+    }
+
+    // Yen's algorithm, built on `dijkstra_excluding`.
+    pub fn k_shortest_paths(&self, from_node_id: i32, to_node_id: i32, k: usize) -> Vec<(Vec<i32>, i32)> {
+        let mut found: Vec<(Vec<i32>, i32)> = Vec::new();
+        let mut candidates: BinaryHeap<Reverse<(i32, Vec<i32>)>> = BinaryHeap::new();
+        let mut seen: HashSet<Vec<i32>> = HashSet::new();
+
+        if k == 0 {
+            return found;
+        }
+
+        match self.dijkstra_excluding(from_node_id, to_node_id, &HashSet::new(), &HashSet::new()) {
+            Some(first) => {
+                seen.insert(first.0.clone());
+                found.push(first);
+            }
+            None => return found,
+        }
+
+        while found.len() < k {
+            let previous_path = found.last().unwrap().0.clone();
+
+            for i in 0..previous_path.len().saturating_sub(1) {
+                let spur_node = previous_path[i];
+                let root_path = &previous_path[..=i];
+
+                let removed_edges: HashSet<(i32, i32)> = found
+                    .iter()
+                    .filter(|(path, _)| path.len() > i + 1 && path[..=i] == *root_path)
+                    .map(|(path, _)| (path[i], path[i + 1]))
+                    .collect();
+                let removed_nodes: HashSet<i32> = root_path[..i].iter().cloned().collect();
+
+                if let Some((spur_path, _)) =
+                    self.dijkstra_excluding(spur_node, to_node_id, &removed_nodes, &removed_edges)
+                {
+                    let mut candidate_path = root_path[..i].to_vec();
+                    candidate_path.extend(spur_path);
+
+                    if seen.contains(&candidate_path) {
+                        continue;
+                    }
+                    seen.insert(candidate_path.clone());
+
+                    let candidate_cost = self.path_cost(&candidate_path);
+                    candidates.push(Reverse((candidate_cost, candidate_path)));
+                }
+            }
+
+            match candidates.pop() {
+                Some(Reverse((cost, path))) => found.push((path, cost)),
+                None => break,
+            }
+        }
+
+        found
+    }
+
+    // Kruskal's algorithm: sort edges by weight, union-find accepts one per new component.
+    pub fn minimum_spanning_tree(&self) -> Vec<Edge> {
+        let ids: Vec<i32> = self.nodes.keys().cloned().collect();
+        let index: HashMap<i32, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        // `add_edge` stores both directions of every undirected edge; keep only one copy.
+        // A directed graph has no such duplicate to collapse, so every edge is kept.
+        let mut unique_edges: Vec<&Edge> = self
+            .edges
+            .values()
+            .flatten()
+            .filter(|edge| self.directed || edge.node_a_id <= edge.node_b_id)
+            .collect();
+        unique_edges.sort_by_key(|edge| edge.weight);
+
+        let mut union_find = UnionFind::new(ids.len());
+        let mut mst = Vec::new();
+
+        for edge in unique_edges {
+            let a = index[&edge.node_a_id];
+            let b = index[&edge.node_b_id];
+            if union_find.union(a, b) {
+                mst.push(edge.clone());
+                if mst.len() == ids.len().saturating_sub(1) {
+                    break;
+                }
+            }
+        }
+
+        mst
+    }
+
+    pub fn connected_components(&self) -> Vec<Vec<i32>> {
+        let ids: Vec<i32> = self.nodes.keys().cloned().collect();
+        let index: HashMap<i32, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut union_find = UnionFind::new(ids.len());
+        for edges in self.edges.values() {
+            for edge in edges {
+                union_find.union(index[&edge.node_a_id], index[&edge.node_b_id]);
+            }
+        }
+
+        let mut components: HashMap<usize, Vec<i32>> = HashMap::new();
+        for (i, &id) in ids.iter().enumerate() {
+            let root = union_find.find(i);
+            components.entry(root).or_default().push(id);
+        }
+
+        components.into_values().collect()
+    }
+
+    // Same loop as `kkomatsu_dijkstra`, but returns the distance to every reachable node
+    // instead of only the target's.
+    fn single_source_distances(&self, from_node_id: i32) -> HashMap<i32, i32> {
+        let mut distances = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        distances.insert(from_node_id, 0);
+        heap.push(State { cost: 0, position: from_node_id });
+
+        while let Some(State { cost, position }) = heap.pop() {
+            if let Some(&current_cost) = distances.get(&position) {
+                if cost > current_cost {
+                    continue;
+                }
+            }
+
+            if let Some(edges) = self.edges.get(&position) {
+                for edge in edges {
+                    let next_cost = cost + edge.weight;
+                    let next_position = edge.node_b_id;
+
+                    if next_cost < *distances.get(&next_position).unwrap_or(&i32::MAX) {
+                        heap.push(State { cost: next_cost, position: next_position });
+                        distances.insert(next_position, next_cost);
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+
+    // Wasserman-Faust normalized closeness centrality.
+    pub fn closeness_centrality(&self) -> HashMap<i32, f64> {
+        let n = self.nodes.len();
+        let mut centrality = HashMap::new();
+
+        for &node_id in self.nodes.keys() {
+            let distances = self.single_source_distances(node_id);
+            let reachable = distances.len() - 1;
+            let sum_of_distances: i32 = distances
+                .iter()
+                .filter(|(&id, _)| id != node_id)
+                .map(|(_, &d)| d)
+                .sum();
+
+            let score = if reachable == 0 || n <= 1 || sum_of_distances == 0 {
+                0.0
+            } else {
+                (reachable as f64 / sum_of_distances as f64) * (reachable as f64 / (n - 1) as f64)
+            };
+
+            centrality.insert(node_id, score);
+        }
+
+        centrality
+    }
+
 }
 
 
@@ -200,4 +601,268 @@ mod tests {
         assert_eq!(graph.kkomatsu_dijkstra(1, 3), 15);
         assert_eq!(graph.kkomatsu_dijkstra(2, 4), 13);
     }
+
+    #[test]
+    fn test_a_star_single_node() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+
+        assert_eq!(graph.a_star(1, 1), 0);
+        assert_eq!(graph.a_star(1, 2), i32::MAX);
+    }
+
+    #[test]
+    fn test_a_star_matches_dijkstra() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+        graph.add_node(Node { id: 4, x: 3, y: 3 });
+
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 2,
+            weight: 5,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 2,
+            node_b_id: 3,
+            weight: 10,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 3,
+            node_b_id: 4,
+            weight: 3,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 4,
+            weight: 20,
+        });
+
+        assert_eq!(graph.a_star(1, 4), graph.kkomatsu_dijkstra(1, 4));
+        assert_eq!(graph.a_star(1, 3), graph.kkomatsu_dijkstra(1, 3));
+        assert_eq!(graph.a_star(2, 4), graph.kkomatsu_dijkstra(2, 4));
+    }
+
+    #[test]
+    fn test_a_star_stays_admissible_on_diagonal_geometry() {
+        // S-A and A-T each weigh exactly their Euclidean length, so the
+        // heuristic is admissible and the optimal route (9, via A) must win
+        // over the direct S-T edge (10) even though A sits off-axis from T.
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 }); // S
+        graph.add_node(Node { id: 2, x: 0, y: 4 }); // A
+        graph.add_node(Node { id: 3, x: 3, y: 0 }); // T
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 4 });
+        graph.add_edge(Edge { node_a_id: 2, node_b_id: 3, weight: 5 });
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 3, weight: 10 });
+
+        assert_eq!(graph.a_star(1, 3), 9);
+        assert_eq!(graph.a_star(1, 3), graph.kkomatsu_dijkstra(1, 3));
+    }
+
+    #[test]
+    fn test_a_star_does_not_panic_on_edge_to_unknown_node() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 5 });
+        // Node 99 was never `add_node`'d; `kkomatsu_dijkstra` never looks up
+        // `self.nodes` for it, and `a_star`'s heuristic lookup must not panic on it.
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 99, weight: 1 });
+
+        assert_eq!(graph.a_star(1, 2), graph.kkomatsu_dijkstra(1, 2));
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+
+        assert_eq!(graph.shortest_path(1, 2), None);
+    }
+
+    #[test]
+    fn test_shortest_path_returns_route_and_cost() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+        graph.add_node(Node { id: 4, x: 3, y: 3 });
+
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 2,
+            weight: 5,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 2,
+            node_b_id: 3,
+            weight: 10,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 3,
+            node_b_id: 4,
+            weight: 3,
+        });
+        graph.add_edge(Edge {
+            node_a_id: 1,
+            node_b_id: 4,
+            weight: 20,
+        });
+
+        assert_eq!(graph.shortest_path(1, 4), Some(vec![1, 2, 3, 4]));
+        assert_eq!(
+            graph.shortest_path_with_cost(1, 4),
+            Some((vec![1, 2, 3, 4], 18))
+        );
+    }
+
+    #[test]
+    fn test_k_shortest_paths_orders_by_cost() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 0 });
+        graph.add_node(Node { id: 3, x: 0, y: 1 });
+        graph.add_node(Node { id: 4, x: 1, y: 1 });
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 1 });
+        graph.add_edge(Edge { node_a_id: 2, node_b_id: 4, weight: 1 });
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 3, weight: 5 });
+        graph.add_edge(Edge { node_a_id: 3, node_b_id: 4, weight: 1 });
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 4, weight: 10 });
+
+        let paths = graph.k_shortest_paths(1, 4, 3);
+
+        assert_eq!(
+            paths,
+            vec![
+                (vec![1, 2, 4], 2),
+                (vec![1, 3, 4], 6),
+                (vec![1, 4], 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_k_shortest_paths_stops_when_exhausted() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 1 });
+
+        assert_eq!(graph.k_shortest_paths(1, 2, 5), vec![(vec![1, 2], 1)]);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_zero_returns_empty() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 1 });
+
+        assert_eq!(graph.k_shortest_paths(1, 2, 0), Vec::new());
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_skips_costlier_cycles() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+        graph.add_node(Node { id: 4, x: 3, y: 3 });
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 1 });
+        graph.add_edge(Edge { node_a_id: 2, node_b_id: 3, weight: 2 });
+        graph.add_edge(Edge { node_a_id: 3, node_b_id: 4, weight: 3 });
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 3, weight: 4 });
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 4, weight: 10 });
+
+        let mst = graph.minimum_spanning_tree();
+        let total_weight: i32 = mst.iter().map(|edge| edge.weight).sum();
+
+        assert_eq!(mst.len(), 3);
+        assert_eq!(total_weight, 6);
+    }
+
+    #[test]
+    fn test_connected_components_finds_isolated_node() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+        graph.add_node(Node { id: 5, x: 4, y: 4 });
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 1 });
+        graph.add_edge(Edge { node_a_id: 2, node_b_id: 3, weight: 1 });
+
+        let mut components: Vec<Vec<i32>> = graph
+            .connected_components()
+            .into_iter()
+            .map(|mut component| {
+                component.sort();
+                component
+            })
+            .collect();
+        components.sort();
+
+        assert_eq!(components, vec![vec![1, 2, 3], vec![5]]);
+    }
+
+    #[test]
+    fn test_closeness_centrality_on_undirected_path() {
+        let mut graph = Graph::new();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 1 });
+        graph.add_edge(Edge { node_a_id: 2, node_b_id: 3, weight: 1 });
+
+        let centrality = graph.closeness_centrality();
+
+        assert!((centrality[&1] - 2.0 / 3.0).abs() < 1e-9);
+        assert!((centrality[&2] - 1.0).abs() < 1e-9);
+        assert!((centrality[&3] - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_closeness_centrality_on_directed_chain() {
+        let mut graph = Graph::directed();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 2, weight: 1 });
+        graph.add_edge(Edge { node_a_id: 2, node_b_id: 3, weight: 1 });
+
+        let centrality = graph.closeness_centrality();
+
+        assert!((centrality[&1] - 2.0 / 3.0).abs() < 1e-9);
+        assert!((centrality[&2] - 0.5).abs() < 1e-9);
+        assert_eq!(centrality[&3], 0.0);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_keeps_directed_edges_in_either_id_order() {
+        let mut graph = Graph::directed();
+        graph.add_node(Node { id: 1, x: 0, y: 0 });
+        graph.add_node(Node { id: 2, x: 1, y: 1 });
+        graph.add_node(Node { id: 3, x: 2, y: 2 });
+
+        graph.add_edge(Edge { node_a_id: 2, node_b_id: 1, weight: 5 });
+        graph.add_edge(Edge { node_a_id: 1, node_b_id: 3, weight: 1 });
+
+        let mst = graph.minimum_spanning_tree();
+        let total_weight: i32 = mst.iter().map(|edge| edge.weight).sum();
+
+        assert_eq!(mst.len(), 2);
+        assert_eq!(total_weight, 6);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod bellman_ford;
+pub mod dijkstra;
+mod path_reconstruction;
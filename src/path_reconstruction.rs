@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+// Walks `predecessors` backward from `to_node_id` to `from_node_id`, then
+// reverses the result into forward order. Shared by `dijkstra::Graph` and
+// `bellman_ford::Graph`'s `shortest_path_with_cost`, which only differ in how
+// `predecessors`/`total_cost` get computed, not in how the route is replayed.
+pub(crate) fn reconstruct_path(
+    predecessors: &HashMap<i32, i32>,
+    from_node_id: i32,
+    to_node_id: i32,
+    total_cost: i32,
+) -> Option<(Vec<i32>, i32)> {
+    // Unreachable unless we started there (no predecessor was ever recorded for it).
+    if from_node_id != to_node_id && !predecessors.contains_key(&to_node_id) {
+        return None;
+    }
+
+    let mut path = vec![to_node_id];
+    let mut visited = HashSet::new();
+    visited.insert(to_node_id);
+    let mut current = to_node_id;
+    while let Some(&prev) = predecessors.get(&current) {
+        // A revisit means `predecessors` holds a cycle (e.g. an undetected
+        // negative cycle) rather than a well-formed route back to the source.
+        if !visited.insert(prev) {
+            return None;
+        }
+        path.push(prev);
+        current = prev;
+    }
+    path.reverse();
+
+    Some((path, total_cost))
+}